@@ -108,6 +108,20 @@ pub struct IndexedString<'a> {
     lines: Vec<Range>,
 }
 
+///
+/// An iterator over the lines of an [`IndexedString`], returned by [`IndexedString::iter_lines`].
+///
+/// Yields `(usize, Range, &str)` tuples of the line number, its byte/character range, and its
+/// text (including any terminating newline). Supports reverse iteration, so it is cheap to tail
+/// a buffer without collecting every line first.
+///
+#[derive(Clone, Debug)]
+pub struct Lines<'a> {
+    string: &'a IndexedString<'a>,
+    front: usize,
+    back: usize,
+}
+
 ///
 /// This is a simplified version of [`std::ops::RangeInclusive`] where each end of the range is an
 /// [`Index`] structure.
@@ -119,12 +133,160 @@ pub struct Range {
 }
 
 ///
-/// An index value is a tuple of the byte index and character index for a character in the string.
+/// An index value is a tuple of the byte index, character index, and UTF-16 code unit index for
+/// a character in the string.
 ///
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Index {
     byte: usize,
     character: usize,
+    utf16: usize,
+}
+
+///
+/// A row/column pair identifying a character within the source string, suitable for editor and
+/// diagnostic tooling that addresses text by line and column rather than by raw offset.
+///
+/// The unit of `column` depends on how the `Position` was constructed; [`IndexedString::position_for_byte`]
+/// produces a byte-based column while [`IndexedString::position_for_character`] produces a character-based
+/// column. The corresponding `*_for_position` methods expect the same unit back.
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    line: usize,
+    column: usize,
+}
+
+///
+/// Configures which characters, or character sequences, [`IndexedString::with_terminators`] treats
+/// as breaking a line.
+///
+/// The default, [`LineTerminator::LfOnly`], matches the behavior of [`IndexedString::from`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineTerminator {
+    /// Only `'\n'` breaks a line.
+    #[default]
+    LfOnly,
+    /// Only the two-character sequence `"\r\n"` breaks a line; a lone `'\r'` or `'\n'` does not.
+    CrLf,
+    /// Any of `'\r'`, `'\n'`, or `"\r\n"` breaks a line, with `"\r\n"` treated as a single break.
+    CrOrLfOrCrLf,
+    /// As [`LineTerminator::CrOrLfOrCrLf`], plus the other characters in Unicode's mandatory
+    /// line-break set: vertical tab (U+000B), form feed (U+000C), next line (U+0085), line
+    /// separator (U+2028), and paragraph separator (U+2029).
+    Unicode,
+}
+
+impl LineTerminator {
+    ///
+    /// Determine whether `c` breaks a line, given the character that follows it (if any).
+    ///
+    /// Returns `None` if `c` is not a line terminator, `Some(false)` if it is a terminator on its
+    /// own, and `Some(true)` if it is a terminator that also consumes `next` (the `"\r\n"` case).
+    ///
+    fn classify(&self, c: char, next: Option<char>) -> Option<bool> {
+        match self {
+            Self::LfOnly => (c == '\n').then_some(false),
+            Self::CrLf => (c == '\r' && next == Some('\n')).then_some(true),
+            Self::CrOrLfOrCrLf => match c {
+                '\r' if next == Some('\n') => Some(true),
+                '\r' | '\n' => Some(false),
+                _ => None,
+            },
+            Self::Unicode => match c {
+                '\r' if next == Some('\n') => Some(true),
+                '\r' | '\n' | '\u{0B}' | '\u{0C}' | '\u{85}' | '\u{2028}' | '\u{2029}' => {
+                    Some(false)
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+///
+/// A single substring match found by [`IndexedString::find_all`], giving the matched range and
+/// the line it starts on.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Match {
+    range: Range,
+    line: usize,
+}
+
+impl Match {
+    ///
+    /// Return the range of the match within the source string.
+    ///
+    pub fn range(&self) -> Range {
+        self.range
+    }
+
+    ///
+    /// Return the line the match starts on.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+///
+/// A stable-compatible stand-in for the standard library's `str::pattern::Pattern`, which is
+/// still unstable; implemented for `&str`, `char`, and `FnMut(char) -> bool` closures so
+/// [`IndexedString::find_all`] accepts the same range of patterns `str::match_indices` does.
+///
+pub trait SearchPattern<'a> {
+    ///
+    /// Return the byte ranges of every match of this pattern within `source`.
+    ///
+    fn find_matches(self, source: &'a str) -> Box<dyn Iterator<Item = (usize, usize)> + 'a>;
+}
+
+impl<'a> SearchPattern<'a> for &'a str {
+    fn find_matches(self, source: &'a str) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
+        let len = self.len();
+        Box::new(
+            source
+                .match_indices(self)
+                .map(move |(start, _)| (start, start + len)),
+        )
+    }
+}
+
+impl<'a> SearchPattern<'a> for char {
+    fn find_matches(self, source: &'a str) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
+        Box::new(
+            source
+                .match_indices(self)
+                .map(move |(start, matched)| (start, start + matched.len())),
+        )
+    }
+}
+
+impl<'a, F> SearchPattern<'a> for F
+where
+    F: FnMut(char) -> bool + 'a,
+{
+    fn find_matches(self, source: &'a str) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
+        Box::new(
+            source
+                .match_indices(self)
+                .map(move |(start, matched)| (start, start + matched.len())),
+        )
+    }
+}
+
+///
+/// The unit an offset or range is expressed in, used internally to share the binary search
+/// between [`IndexedString::line_for_byte`], [`IndexedString::line_for_character`], and
+/// [`IndexedString::line_for_utf16`].
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Unit {
+    Byte,
+    Character,
+    Utf16,
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -137,7 +299,7 @@ pub struct Index {
 
 impl<'a> From<&'a str> for IndexedString<'a> {
     fn from(s: &'a str) -> Self {
-        let lines = Self::make_lines(s);
+        let lines = Self::make_lines(s, LineTerminator::default());
         Self {
             source: Cow::Borrowed(s),
             lines,
@@ -147,7 +309,7 @@ impl<'a> From<&'a str> for IndexedString<'a> {
 
 impl From<String> for IndexedString<'_> {
     fn from(s: String) -> Self {
-        let lines = Self::make_lines(&s);
+        let lines = Self::make_lines(&s, LineTerminator::default());
         Self {
             source: Cow::Owned(s),
             lines,
@@ -161,35 +323,166 @@ impl AsRef<str> for IndexedString<'_> {
     }
 }
 
+impl<'a> IndexedString<'a> {
+    ///
+    /// Construct an indexed string using the given [`LineTerminator`] configuration, rather than
+    /// the `'\n'`-only behavior used by [`IndexedString::from`]. This is useful for indexing
+    /// CRLF files, classic-Mac `\r` files, or text containing other Unicode line separators.
+    ///
+    pub fn with_terminators(source: impl Into<Cow<'a, str>>, terminators: LineTerminator) -> Self {
+        let source = source.into();
+        let lines = Self::make_lines(&source, terminators);
+        Self { source, lines }
+    }
+
+    ///
+    /// Return an iterator over all lines, yielding their line number, range, and text.
+    ///
+    pub fn iter_lines(&'a self) -> Lines<'a> {
+        Lines {
+            string: self,
+            front: 0,
+            back: self.lines.len(),
+        }
+    }
+
+    ///
+    /// Search for every match of `pattern` within the source string, returning each as a
+    /// [`Match`] carrying its line and range. Accepts a `&str`, `char`, or `FnMut(char) -> bool`
+    /// closure, mirroring [`str::match_indices`].
+    ///
+    pub fn find_all<P>(&'a self, pattern: P) -> impl Iterator<Item = Match> + 'a
+    where
+        P: SearchPattern<'a>,
+    {
+        pattern
+            .find_matches(self.as_str())
+            .filter_map(move |(start, end)| {
+                let line = self.line_for_match_start(start)?;
+                let start_index = self.index_for_byte(start)?;
+                let end_index = self.end_index_for_match(start, end)?;
+                Some(Match {
+                    range: Range::new(start_index, end_index),
+                    line,
+                })
+            })
+    }
+
+    // Return the line a match starting at `byte` should be reported against. `line_for_byte`
+    // has no line covering `source.len()` itself, so a trailing zero-width match (as produced
+    // by an empty pattern) is attributed to the last line, or line 0 for an empty source.
+    fn line_for_match_start(&self, byte: usize) -> Option<usize> {
+        if byte == self.source.len() {
+            return Some(self.lines.len().saturating_sub(1));
+        }
+        self.line_for_byte(byte)
+    }
+
+    // Return the `Index` of the character starting at `byte`, computed by scanning the prefix
+    // of its line, since a line records only its start and end indices. `byte == source.len()` is the
+    // one past the last character (as produced by an empty pattern's trailing match, or the
+    // sole match of an empty pattern against an empty source), which has no owning line.
+    fn index_for_byte(&self, byte: usize) -> Option<Index> {
+        if byte == self.source.len() {
+            return Some(match self.as_str().char_indices().last() {
+                None => Index {
+                    byte: 0,
+                    character: 0,
+                    utf16: 0,
+                },
+                Some((offset, c)) => {
+                    let last_char = self.index_for_byte(offset)?;
+                    Index {
+                        byte,
+                        character: last_char.character() + 1,
+                        utf16: last_char.utf16() + c.len_utf16(),
+                    }
+                }
+            });
+        }
+        let line = self.line_for_byte(byte)?;
+        let line_start = self.lines.get(line)?.start();
+        let prefix = &self.line_str(line)?[..byte - line_start.byte()];
+        Some(Index {
+            byte,
+            character: line_start.character() + prefix.chars().count(),
+            utf16: line_start.utf16() + prefix.chars().map(char::len_utf16).sum::<usize>(),
+        })
+    }
+
+    // Return the `Index` of the last character of the match `[start, end)`, with its byte and
+    // UTF-16 components pointing at that character's last byte and last code unit, matching the
+    // convention used for a line's own end index. A zero-length match (as yielded for an empty pattern) has no
+    // character of its own, so both ends of its range are the index at `start`.
+    fn end_index_for_match(&self, start: usize, end: usize) -> Option<Index> {
+        if start == end {
+            return self.index_for_byte(start);
+        }
+        let (offset, c) = self.as_str()[start..end].char_indices().last()?;
+        let last_char_byte = start + offset;
+        let mut index = self.index_for_byte(last_char_byte)?;
+        index.byte = last_char_byte + c.len_utf8() - 1;
+        index.utf16 += c.len_utf16() - 1;
+        Some(index)
+    }
+}
+
 impl IndexedString<'_> {
-    fn make_lines(s: &str) -> Vec<Range> {
+    fn make_lines(s: &str, terminators: LineTerminator) -> Vec<Range> {
         let mut lines: Vec<Range> = Default::default();
-        if !s.is_empty() {
-            let mut start = Index {
-                byte: 0,
-                character: 0,
-            };
-            let mut next = false;
-            let end = s.len() - 1;
-            for (c_i, (b_i, c)) in s.char_indices().enumerate() {
-                if next {
-                    let here = Index {
-                        byte: b_i,
-                        character: c_i,
-                    };
-                    start = here;
-                    next = false;
-                }
-                if c == '\n' || c_i == end {
-                    let here = Index {
-                        byte: b_i,
-                        character: c_i,
-                    };
-                    lines.push(Range { start, end: here });
-                    next = true;
+        if s.is_empty() {
+            return lines;
+        }
+
+        let mut start = Index {
+            byte: 0,
+            character: 0,
+            utf16: 0,
+        };
+        let mut next = false;
+        let final_byte = s.len() - 1;
+        let mut utf16 = 0;
+        let mut chars = s.char_indices().enumerate().peekable();
+
+        while let Some((c_i, (b_i, c))) = chars.next() {
+            if next {
+                start = Index {
+                    byte: b_i,
+                    character: c_i,
+                    utf16,
+                };
+                next = false;
+            }
+
+            utf16 += c.len_utf16();
+
+            let peeked = chars.peek().map(|(_, (_, c))| *c);
+            let classification = terminators.classify(c, peeked);
+
+            let here = if classification == Some(true) {
+                // A `"\r\n"` pair that breaks as a single line; consume the `'\n'` too.
+                let (c_i, (b_i, c)) = chars.next().unwrap();
+                let here = Index {
+                    byte: b_i + c.len_utf8() - 1,
+                    character: c_i,
+                    utf16,
+                };
+                utf16 += c.len_utf16();
+                here
+            } else {
+                Index {
+                    byte: b_i + c.len_utf8() - 1,
+                    character: c_i,
+                    utf16: utf16 - 1,
                 }
+            };
+
+            if classification.is_some() || here.byte == final_byte {
+                lines.push(Range { start, end: here });
+                next = true;
             }
         }
+
         lines
     }
 
@@ -219,7 +512,7 @@ impl IndexedString<'_> {
     /// outside the range of the string return `None`.
     ///
     pub fn line_for_byte(&self, byte: usize) -> Option<usize> {
-        self.line_for(true, byte)
+        self.line_for(Unit::Byte, byte)
     }
 
     ///
@@ -227,29 +520,40 @@ impl IndexedString<'_> {
     /// outside the range of the string return `None`.
     ///
     pub fn line_for_character(&self, character: usize) -> Option<usize> {
-        self.line_for(false, character)
+        self.line_for(Unit::Character, character)
     }
 
-    fn line_for(&self, byte: bool, index: usize) -> Option<usize> {
+    ///
+    /// Return the line containing the provided UTF-16 code unit index. If the index is
+    /// outside the range of the string return `None`.
+    ///
+    pub fn line_for_utf16(&self, utf16: usize) -> Option<usize> {
+        self.line_for(Unit::Utf16, utf16)
+    }
+
+    fn line_for(&self, unit: Unit, index: usize) -> Option<usize> {
+        if self.lines.is_empty() {
+            return None;
+        }
         let start = 0;
-        let end = self.lines.len();
-        self.inner_line_for(byte, index, start, end)
+        let end = self.lines.len() - 1;
+        self.inner_line_for(unit, index, start, end)
     }
 
-    fn inner_line_for(&self, byte: bool, index: usize, start: usize, end: usize) -> Option<usize> {
+    fn inner_line_for(&self, unit: Unit, index: usize, start: usize, end: usize) -> Option<usize> {
         let mid_index = start + ((end - start) / 2);
         let mid_range = self.lines.get(mid_index).unwrap();
-        let mid_range = if byte {
-            mid_range.bytes()
-        } else {
-            mid_range.characters()
+        let mid_range = match unit {
+            Unit::Byte => mid_range.bytes(),
+            Unit::Character => mid_range.characters(),
+            Unit::Utf16 => mid_range.utf16(),
         };
         if mid_range.contains(&index) {
             Some(mid_index)
         } else if mid_index > start && index < *mid_range.start() {
-            self.inner_line_for(byte, index, start, mid_index - 1)
+            self.inner_line_for(unit, index, start, mid_index - 1)
         } else if mid_index < end && index > *mid_range.end() {
-            self.inner_line_for(byte, index, mid_index + 1, end)
+            self.inner_line_for(unit, index, mid_index + 1, end)
         } else {
             None
         }
@@ -271,6 +575,14 @@ impl IndexedString<'_> {
         self.lines.get(line).map(|range| range.characters())
     }
 
+    ///
+    /// Return the UTF-16 code unit range (including any terminating newline) for the provided
+    /// line number. If the line number is outside the range of the string return `None`.
+    ///
+    pub fn utf16_range_for_line(&self, line: usize) -> Option<RangeInclusive<usize>> {
+        self.lines.get(line).map(|range| range.utf16())
+    }
+
     ///
     /// Return the line, as a string, (including any terminating newline) for the provided
     /// line number. If the line number is outside the range of the string return `None`.
@@ -282,6 +594,47 @@ impl IndexedString<'_> {
             None
         }
     }
+
+    ///
+    /// Return the line/column position of the provided byte index, with the column expressed
+    /// in bytes. If the index is outside the range of the string return `None`.
+    ///
+    pub fn position_for_byte(&self, byte: usize) -> Option<Position> {
+        let line = self.line_for_byte(byte)?;
+        let range = self.lines.get(line).unwrap();
+        Some(Position::new(line, byte - range.start().byte()))
+    }
+
+    ///
+    /// Return the line/column position of the provided character index, with the column
+    /// expressed in characters. If the index is outside the range of the string return `None`.
+    ///
+    pub fn position_for_character(&self, character: usize) -> Option<Position> {
+        let line = self.line_for_character(character)?;
+        let range = self.lines.get(line).unwrap();
+        Some(Position::new(line, character - range.start().character()))
+    }
+
+    ///
+    /// Return the byte index for the provided position, whose column is expected to be in
+    /// bytes. If the position's line or column is outside the range of the string return `None`.
+    ///
+    pub fn byte_for_position(&self, position: Position) -> Option<usize> {
+        let range = self.lines.get(position.line())?;
+        let byte = range.start().byte() + position.column();
+        (byte <= range.end().byte()).then_some(byte)
+    }
+
+    ///
+    /// Return the character index for the provided position, whose column is expected to be in
+    /// characters. If the position's line or column is outside the range of the string return
+    /// `None`.
+    ///
+    pub fn character_for_position(&self, position: Position) -> Option<usize> {
+        let range = self.lines.get(position.line())?;
+        let character = range.start().character() + position.column();
+        (character <= range.end().character()).then_some(character)
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -321,16 +674,27 @@ impl Range {
     pub fn characters(&self) -> RangeInclusive<usize> {
         self.start.character..=self.end.character
     }
+
+    ///
+    /// Return a standard library range for just the UTF-16 code unit indices.
+    ///
+    pub fn utf16(&self) -> RangeInclusive<usize> {
+        self.start.utf16..=self.end.utf16
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
 
 impl Index {
     ///
-    /// Construct a new index with byte and character indices.
+    /// Construct a new index with byte, character, and UTF-16 code unit indices.
     ///
-    pub fn new(byte: usize, character: usize) -> Self {
-        Self { byte, character }
+    pub fn new(byte: usize, character: usize, utf16: usize) -> Self {
+        Self {
+            byte,
+            character,
+            utf16,
+        }
     }
 
     ///
@@ -346,6 +710,83 @@ impl Index {
     pub fn character(&self) -> usize {
         self.character
     }
+
+    ///
+    /// Return the UTF-16 code unit part of this index.
+    ///
+    pub fn utf16(&self) -> usize {
+        self.utf16
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl Position {
+    ///
+    /// Construct a new position from a line and column.
+    ///
+    pub fn new(line: usize, column: usize) -> Self {
+        Self { line, column }
+    }
+
+    ///
+    /// Return the line part of this position.
+    ///
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    ///
+    /// Return the column part of this position.
+    ///
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+
+impl<'a> Lines<'a> {
+    fn get(&self, line: usize) -> Option<(usize, Range, &'a str)> {
+        self.string
+            .lines
+            .get(line)
+            .map(|range| (line, *range, &self.string.source[range.bytes()]))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = (usize, Range, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let line = self.get(self.front);
+        self.front += 1;
+        line
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Lines<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.get(self.back)
+    }
+}
+
+impl ExactSizeIterator for Lines<'_> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
 }
 
 // ------------------------------------------------------------------------------------------------
@@ -400,4 +841,167 @@ mod tests {
             .into_iter()
             .for_each(|(line, string)| assert_eq!(indexed.line_str(line), Some(string)));
     }
+
+    #[test]
+    fn test_position_conversions() {
+        let lines = "aa\nbbb\ncccc\ndd";
+        let indexed = IndexedString::from(lines);
+
+        assert_eq!(indexed.position_for_byte(4), Some(Position::new(1, 1)));
+        assert_eq!(indexed.position_for_character(8), Some(Position::new(2, 1)));
+
+        assert_eq!(indexed.byte_for_position(Position::new(1, 1)), Some(4));
+        assert_eq!(indexed.character_for_position(Position::new(2, 1)), Some(8));
+
+        assert_eq!(indexed.position_for_byte(100), None);
+        assert_eq!(indexed.byte_for_position(Position::new(100, 0)), None);
+    }
+
+    #[test]
+    fn test_utf16_index() {
+        let lines = "a\u{1F600}b\nc";
+        let indexed = IndexedString::from(lines);
+
+        println!("{:#?}", indexed);
+        assert_eq!(indexed.lines(), 2);
+
+        // "😀" is 4 bytes, 1 character, and 2 UTF-16 code units.
+        assert_eq!(indexed.utf16_range_for_line(0), Some(0..=4));
+        assert_eq!(indexed.character_range_for_line(0), Some(0..=3));
+        assert_eq!(indexed.byte_range_for_line(0), Some(0..=6));
+
+        assert_eq!(indexed.line_for_utf16(3), Some(0));
+        assert_eq!(indexed.line_for_utf16(5), Some(1));
+    }
+
+    #[test]
+    fn test_line_terminators() {
+        let mixed = "a\r\nb\rc\nd";
+
+        let crlf = IndexedString::with_terminators(mixed, LineTerminator::CrLf);
+        assert_eq!(crlf.lines(), 2);
+        assert_eq!(crlf.line_str(0), Some("a\r\n"));
+        assert_eq!(crlf.line_str(1), Some("b\rc\nd"));
+
+        let any = IndexedString::with_terminators(mixed, LineTerminator::CrOrLfOrCrLf);
+        assert_eq!(any.lines(), 4);
+        assert_eq!(any.line_str(0), Some("a\r\n"));
+        assert_eq!(any.line_str(1), Some("b\r"));
+        assert_eq!(any.line_str(2), Some("c\n"));
+        assert_eq!(any.line_str(3), Some("d"));
+
+        let unicode =
+            IndexedString::with_terminators("a\u{85}b\u{2028}c\u{2029}d", LineTerminator::Unicode);
+        assert_eq!(unicode.lines(), 4);
+        assert_eq!(unicode.line_str(0), Some("a\u{85}"));
+        assert_eq!(unicode.line_str(1), Some("b\u{2028}"));
+        assert_eq!(unicode.line_str(2), Some("c\u{2029}"));
+        assert_eq!(unicode.line_str(3), Some("d"));
+    }
+
+    #[test]
+    fn test_iter_lines() {
+        let indexed = IndexedString::from("aa\nbbb\ncccc\ndd");
+
+        let forward: Vec<&str> = indexed.iter_lines().map(|(_, _, text)| text).collect();
+        assert_eq!(forward, vec!["aa\n", "bbb\n", "cccc\n", "dd"]);
+
+        let backward: Vec<&str> = indexed
+            .iter_lines()
+            .rev()
+            .map(|(_, _, text)| text)
+            .collect();
+        assert_eq!(backward, vec!["dd", "cccc\n", "bbb\n", "aa\n"]);
+
+        assert_eq!(indexed.iter_lines().len(), 4);
+
+        let (line, range, text) = indexed.iter_lines().nth(2).unwrap();
+        assert_eq!(line, 2);
+        assert_eq!(
+            range,
+            Range::new(Index::new(7, 7, 7), Index::new(11, 11, 11))
+        );
+        assert_eq!(text, "cccc\n");
+    }
+
+    #[test]
+    fn test_find_all_str_pattern() {
+        let indexed = IndexedString::from("aa\nbbb\ncccc\ndd");
+
+        let matches: Vec<Match> = indexed.find_all("cc").collect();
+        assert_eq!(
+            matches,
+            vec![
+                Match {
+                    range: Range::new(Index::new(7, 7, 7), Index::new(8, 8, 8)),
+                    line: 2,
+                },
+                Match {
+                    range: Range::new(Index::new(9, 9, 9), Index::new(10, 10, 10)),
+                    line: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_all_char_pattern() {
+        let indexed = IndexedString::from("aa\nbbb\ncccc\ndd");
+
+        let matches: Vec<(usize, Range)> = indexed
+            .find_all('b')
+            .map(|m| (m.line(), m.range()))
+            .collect();
+        assert_eq!(
+            matches,
+            vec![
+                (1, Range::new(Index::new(3, 3, 3), Index::new(3, 3, 3))),
+                (1, Range::new(Index::new(4, 4, 4), Index::new(4, 4, 4))),
+                (1, Range::new(Index::new(5, 5, 5), Index::new(5, 5, 5))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_all_closure_pattern() {
+        let indexed = IndexedString::from("a1\nb22\nc333");
+
+        let matches: Vec<&str> = indexed
+            .find_all(|c: char| c.is_ascii_digit())
+            .map(|m| &indexed.as_str()[m.range().bytes()])
+            .collect();
+        assert_eq!(matches, vec!["1", "2", "2", "3", "3", "3"]);
+    }
+
+    #[test]
+    fn test_find_all_empty_pattern() {
+        let indexed = IndexedString::from("ab");
+
+        let matches: Vec<(usize, Range)> = indexed
+            .find_all("")
+            .map(|m| (m.line(), m.range()))
+            .collect();
+        assert_eq!(
+            matches,
+            vec![
+                (0, Range::new(Index::new(0, 0, 0), Index::new(0, 0, 0))),
+                (0, Range::new(Index::new(1, 1, 1), Index::new(1, 1, 1))),
+                (0, Range::new(Index::new(2, 2, 2), Index::new(2, 2, 2))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_all_empty_pattern_on_empty_source() {
+        let indexed = IndexedString::from("");
+
+        let matches: Vec<(usize, Range)> = indexed
+            .find_all("")
+            .map(|m| (m.line(), m.range()))
+            .collect();
+        assert_eq!(
+            matches,
+            vec![(0, Range::new(Index::new(0, 0, 0), Index::new(0, 0, 0)))]
+        );
+    }
 }